@@ -2,9 +2,13 @@
 //!
 //! Provides IPv6Address and IPv6Network types with full address manipulation.
 
+pub mod nat64;
+pub mod wire;
+
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::net::Ipv6Addr;
+use std::iter::FusedIterator;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -12,18 +16,107 @@ use thiserror::Error;
 pub enum Ipv6Error {
     #[error("Invalid IPv6 address: {0}")]
     InvalidAddress(String),
-    #[error("Invalid IPv6 network: {0}")]
-    InvalidNetwork(String),
     #[error("Invalid prefix length: {0}")]
     InvalidPrefix(String),
     #[error("Network too large to enumerate (prefix < /64)")]
     NetworkTooLarge,
     #[error("Cannot divide network: {0}")]
     DivisionError(String),
+    #[error("Network is missing a prefix length (expected address/prefix, e.g. 2001:db8::/32)")]
+    MissingPrefixLength,
+    #[error("Could not parse address '{0}'")]
+    MalformedAddress(String),
+    #[error("Prefix length '{0}' is out of range (must be 0-128)")]
+    PrefixOutOfRange(String),
+    #[error("{input} is not a network address for /{prefix_len} (host bits are set); did you mean {suggested}?")]
+    NotNetworkAddress {
+        input: String,
+        prefix_len: u8,
+        suggested: String,
+    },
+    #[error("Truncated IPv6 header: buffer is only {0} bytes, need at least 40")]
+    TruncatedHeader(usize),
+    #[error("Malformed IPv6 header: {0}")]
+    MalformedHeader(String),
 }
 
 pub type Result<T> = std::result::Result<T, Ipv6Error>;
 
+/// Multicast scope of an `ff00::/8` address, decoded from the 4-bit scope
+/// nibble (RFC 4291 section 2.7, RFC 7346).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MulticastScope {
+    Reserved,
+    InterfaceLocal,
+    LinkLocal,
+    RealmLocal,
+    AdminLocal,
+    SiteLocal,
+    OrganizationLocal,
+    Global,
+    /// A scope value not assigned a meaning by the IANA registry.
+    Unassigned(u8),
+}
+
+impl MulticastScope {
+    fn from_nibble(n: u8) -> Self {
+        match n {
+            1 => MulticastScope::InterfaceLocal,
+            2 => MulticastScope::LinkLocal,
+            3 => MulticastScope::RealmLocal,
+            4 => MulticastScope::AdminLocal,
+            5 => MulticastScope::SiteLocal,
+            8 => MulticastScope::OrganizationLocal,
+            0xe => MulticastScope::Global,
+            0 | 0xf => MulticastScope::Reserved,
+            other => MulticastScope::Unassigned(other),
+        }
+    }
+}
+
+impl fmt::Display for MulticastScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MulticastScope::Reserved => write!(f, "Reserved"),
+            MulticastScope::InterfaceLocal => write!(f, "Interface-Local"),
+            MulticastScope::LinkLocal => write!(f, "Link-Local"),
+            MulticastScope::RealmLocal => write!(f, "Realm-Local"),
+            MulticastScope::AdminLocal => write!(f, "Admin-Local"),
+            MulticastScope::SiteLocal => write!(f, "Site-Local"),
+            MulticastScope::OrganizationLocal => write!(f, "Organization-Local"),
+            MulticastScope::Global => write!(f, "Global"),
+            MulticastScope::Unassigned(n) => write!(f, "Unassigned ({:#x})", n),
+        }
+    }
+}
+
+/// Multicast flags of an `ff00::/8` address: transient (T, RFC 4291),
+/// prefix-based (P, RFC 3306) and rendezvous-point-embedded (R, RFC 3956).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MulticastFlags {
+    pub rendezvous: bool,
+    pub prefix_based: bool,
+    pub transient: bool,
+}
+
+impl fmt::Display for MulticastFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.transient {
+            return write!(f, "well-known (permanently assigned)");
+        }
+
+        let mut parts = Vec::new();
+        if self.prefix_based {
+            parts.push("prefix-based (RFC 3306)");
+        }
+        if self.rendezvous {
+            parts.push("embedded-RP (RFC 3956)");
+        }
+        parts.push("transient");
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
 /// Represents an IPv6 address with utilities for manipulation and analysis.
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct IPv6Address {
@@ -87,6 +180,31 @@ impl IPv6Address {
         self.addr.is_multicast()
     }
 
+    /// Decode the 4-bit multicast scope nibble (RFC 4291 / RFC 7346),
+    /// or `None` if this is not a multicast address.
+    pub fn multicast_scope(&self) -> Option<MulticastScope> {
+        if !self.is_multicast() {
+            return None;
+        }
+        let scope_nibble = (self.addr.segments()[0] & 0x000f) as u8;
+        Some(MulticastScope::from_nibble(scope_nibble))
+    }
+
+    /// Decode the multicast flags nibble (transient/prefix-based/rendezvous
+    /// per RFC 3306 and RFC 3956), or `None` if this is not a multicast
+    /// address.
+    pub fn multicast_flags(&self) -> Option<MulticastFlags> {
+        if !self.is_multicast() {
+            return None;
+        }
+        let flags_nibble = ((self.addr.segments()[0] & 0x00f0) >> 4) as u8;
+        Some(MulticastFlags {
+            rendezvous: flags_nibble & 0b0100 != 0,
+            prefix_based: flags_nibble & 0b0010 != 0,
+            transient: flags_nibble & 0b0001 != 0,
+        })
+    }
+
     /// Check if address is global unicast.
     pub fn is_global(&self) -> bool {
         let segments = self.addr.segments();
@@ -105,6 +223,34 @@ impl IPv6Address {
         self.addr.is_unspecified()
     }
 
+    /// Check if address is within the documentation range (2001:db8::/32, RFC 3849).
+    pub fn is_documentation(&self) -> bool {
+        let segments = self.addr.segments();
+        segments[0] == 0x2001 && segments[1] == 0x0db8
+    }
+
+    /// Check if address is an IPv4-mapped IPv6 address (::ffff:0:0/96, RFC 4291).
+    pub fn is_ipv4_mapped(&self) -> bool {
+        let segments = self.addr.segments();
+        segments[0..5] == [0, 0, 0, 0, 0] && segments[5] == 0xffff
+    }
+
+    /// Extract the embedded IPv4 address from an IPv4-mapped address
+    /// (::ffff:0:0/96, RFC 4291), or `None` if this isn't one.
+    pub fn mapped_ipv4(&self) -> Option<Ipv4Addr> {
+        if !self.is_ipv4_mapped() {
+            return None;
+        }
+        let octets = self.addr.octets();
+        Some(Ipv4Addr::new(octets[12], octets[13], octets[14], octets[15]))
+    }
+
+    /// Check if address is within the benchmarking range (2001:2::/48, RFC 5180).
+    pub fn is_benchmarking(&self) -> bool {
+        let segments = self.addr.segments();
+        segments[0] == 0x2001 && segments[1] == 0x0002 && segments[2] == 0
+    }
+
     /// Convert address to binary representation.
     pub fn to_binary(&self) -> String {
         let octets = self.addr.octets();
@@ -127,6 +273,12 @@ impl IPv6Address {
             "Unique Local (ULA)"
         } else if self.is_multicast() {
             "Multicast"
+        } else if self.is_ipv4_mapped() {
+            "IPv4-Mapped"
+        } else if self.is_documentation() {
+            "Documentation"
+        } else if self.is_benchmarking() {
+            "Benchmarking"
         } else if self.is_global() {
             "Global Unicast"
         } else if self.is_unspecified() {
@@ -181,32 +333,42 @@ pub struct IPv6Network {
 
 impl IPv6Network {
     /// Create a new IPv6 network from CIDR notation.
+    ///
+    /// The address must already be the canonical network address for the
+    /// given prefix (i.e. no host bits set); otherwise this returns
+    /// [`Ipv6Error::NotNetworkAddress`] with the corrected network as a
+    /// suggestion. Use [`IPv6Network::from_u128`] when you want the address
+    /// masked down silently instead.
     pub fn new(network: &str) -> Result<Self> {
         let (addr_str, prefix_str) = network
             .split_once('/')
-            .ok_or_else(|| Ipv6Error::InvalidNetwork("Missing prefix length".to_string()))?;
+            .ok_or(Ipv6Error::MissingPrefixLength)?;
 
         let prefix_len: u8 = prefix_str
             .parse()
-            .map_err(|_| Ipv6Error::InvalidPrefix(prefix_str.to_string()))?;
+            .map_err(|_| Ipv6Error::PrefixOutOfRange(prefix_str.to_string()))?;
 
         if prefix_len > 128 {
-            return Err(Ipv6Error::InvalidPrefix(format!(
-                "Prefix {} exceeds 128",
-                prefix_len
-            )));
+            return Err(Ipv6Error::PrefixOutOfRange(prefix_str.to_string()));
         }
 
         let addr = Ipv6Addr::from_str(addr_str)
-            .map_err(|e| Ipv6Error::InvalidAddress(e.to_string()))?;
+            .map_err(|_| Ipv6Error::MalformedAddress(addr_str.to_string()))?;
 
-        // Mask to network address
         let mask = Self::prefix_to_mask(prefix_len);
-        let network_int = u128::from(addr) & mask;
-        let network_addr = Ipv6Addr::from(network_int);
+        let addr_int = u128::from(addr);
+        let network_int = addr_int & mask;
+
+        if network_int != addr_int {
+            return Err(Ipv6Error::NotNetworkAddress {
+                input: network.to_string(),
+                prefix_len,
+                suggested: format!("{}/{}", Ipv6Addr::from(network_int), prefix_len),
+            });
+        }
 
         Ok(Self {
-            network_addr,
+            network_addr: Ipv6Addr::from(network_int),
             prefix_len,
         })
     }
@@ -319,6 +481,29 @@ impl IPv6Network {
         })
     }
 
+    /// Iterate over every address in the network, from the network address
+    /// through the broadcast address.
+    pub fn hosts(&self) -> IPv6AddressRange {
+        IPv6AddressRange::new(&self.network_address(), &self.broadcast_address())
+    }
+
+    /// Construct a network directly from an address integer and prefix length,
+    /// masking to the network address.
+    pub fn from_u128(addr: u128, prefix_len: u8) -> Result<Self> {
+        if prefix_len > 128 {
+            return Err(Ipv6Error::InvalidPrefix(format!(
+                "Prefix {} exceeds 128",
+                prefix_len
+            )));
+        }
+
+        let mask = Self::prefix_to_mask(prefix_len);
+        Ok(Self {
+            network_addr: Ipv6Addr::from(addr & mask),
+            prefix_len,
+        })
+    }
+
     fn prefix_to_mask(prefix_len: u8) -> u128 {
         if prefix_len == 0 {
             0
@@ -330,6 +515,69 @@ impl IPv6Network {
     fn prefix_to_host_mask(prefix_len: u8) -> u128 {
         !Self::prefix_to_mask(prefix_len)
     }
+
+    /// Collapse a set of networks into the minimal equivalent covering set:
+    /// drop subnets already covered by another entry, then repeatedly merge
+    /// adjacent sibling networks into their shared supernet until no further
+    /// merge is possible.
+    pub fn aggregate(nets: &[IPv6Network]) -> Vec<IPv6Network> {
+        let mut nets: Vec<IPv6Network> = nets.to_vec();
+        nets.sort_by_key(|n| (n.network_address().to_u128(), n.prefix_len()));
+
+        let mut kept: Vec<IPv6Network> = Vec::new();
+        for net in nets {
+            let contained = kept
+                .iter()
+                .any(|k| k.prefix_len() <= net.prefix_len() && k.contains(&net.network_address()));
+            if !contained {
+                kept.push(net);
+            }
+        }
+
+        loop {
+            let mut merged = false;
+            let mut next: Vec<IPv6Network> = Vec::with_capacity(kept.len());
+            let mut i = 0;
+            while i < kept.len() {
+                if i + 1 < kept.len()
+                    && Self::are_siblings(&kept[i], &kept[i + 1])
+                {
+                    next.push(kept[i].supernet(1).expect("sibling prefix is never 0"));
+                    i += 2;
+                    merged = true;
+                } else {
+                    next.push(kept[i].clone());
+                    i += 1;
+                }
+            }
+            kept = next;
+            if !merged {
+                break;
+            }
+            kept.sort_by_key(|n| (n.network_address().to_u128(), n.prefix_len()));
+        }
+
+        kept
+    }
+
+    /// Check whether `a` and `b` are the two halves of a common `/(p-1)` parent.
+    fn are_siblings(a: &IPv6Network, b: &IPv6Network) -> bool {
+        let p = a.prefix_len();
+        if p == 0 || p != b.prefix_len() {
+            return false;
+        }
+
+        let block_size = 1u128 << (128 - p);
+        let a_addr = a.network_address().to_u128();
+        let b_addr = b.network_address().to_u128();
+
+        let aligned = match block_size.checked_mul(2) {
+            Some(parent_size) => a_addr.is_multiple_of(parent_size),
+            None => a_addr == 0,
+        };
+
+        aligned && b_addr == a_addr + block_size
+    }
 }
 
 impl fmt::Display for IPv6Network {
@@ -352,6 +600,104 @@ impl FromStr for IPv6Network {
     }
 }
 
+/// Summarize the inclusive address range `start..=end` into the smallest
+/// list of aligned `IPv6Network` blocks that exactly cover it.
+///
+/// At each step, the next block's size is bounded both by the alignment of
+/// its start address (how many trailing zero bits it has) and by how much
+/// of the remaining range it can cover without overrunning `end`.
+pub fn summarize_address_range(start: &IPv6Address, end: &IPv6Address) -> Result<Vec<IPv6Network>> {
+    let mut s = start.to_u128();
+    let e = end.to_u128();
+
+    if s > e {
+        return Err(Ipv6Error::InvalidAddress(format!(
+            "range start {} is after end {}",
+            start, end
+        )));
+    }
+
+    let mut blocks = Vec::new();
+    loop {
+        let alignment = if s == 0 { 128 } else { s.trailing_zeros() };
+
+        // `span` (the number of addresses from `s` to `e`) is `2^128` only
+        // when `s == 0` and `e == u128::MAX`, which doesn't fit in a u128.
+        let remaining_fit = if s == 0 && e == u128::MAX {
+            128
+        } else {
+            let span = e - s + 1;
+            127 - span.leading_zeros()
+        };
+
+        let max_size = alignment.min(remaining_fit);
+        let prefix_len = 128 - max_size as u8;
+        blocks.push(IPv6Network::from_u128(s, prefix_len)?);
+
+        if max_size == 128 {
+            break;
+        }
+
+        // A block ending at the top of the address space would overflow `s`.
+        match s.checked_add(1u128 << max_size) {
+            Some(next) if next <= e => s = next,
+            _ => break,
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// Lazy iterator over the individual addresses in an inclusive `u128` range,
+/// mirroring ipnet's `IpAddrRange`.
+#[derive(Clone)]
+pub struct IPv6AddressRange {
+    next: Option<u128>,
+    last: u128,
+}
+
+impl IPv6AddressRange {
+    /// Create an iterator over `start..=end` (inclusive of both ends).
+    pub fn new(start: &IPv6Address, end: &IPv6Address) -> Self {
+        let start = start.to_u128();
+        let last = end.to_u128();
+        Self {
+            next: if start <= last { Some(start) } else { None },
+            last,
+        }
+    }
+}
+
+impl Iterator for IPv6AddressRange {
+    type Item = IPv6Address;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == self.last {
+            None
+        } else {
+            Some(current + 1)
+        };
+        Some(IPv6Address {
+            addr: Ipv6Addr::from(current),
+            zone_id: None,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            None => (0, Some(0)),
+            Some(next) => {
+                let remaining = self.last - next + 1;
+                let remaining = usize::try_from(remaining).unwrap_or(usize::MAX);
+                (remaining, Some(remaining))
+            }
+        }
+    }
+}
+
+impl FusedIterator for IPv6AddressRange {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -382,6 +728,80 @@ mod tests {
         assert!(IPv6Address::new("fd00::1").unwrap().is_unique_local());
     }
 
+    #[test]
+    fn test_special_purpose_predicates() {
+        assert!(IPv6Address::new("2001:db8::1").unwrap().is_documentation());
+        assert!(!IPv6Address::new("2001:db9::1").unwrap().is_documentation());
+
+        assert!(IPv6Address::new("::ffff:192.0.2.1").unwrap().is_ipv4_mapped());
+        assert!(!IPv6Address::new("2001:db8::1").unwrap().is_ipv4_mapped());
+
+        assert_eq!(
+            IPv6Address::new("::ffff:192.0.2.1").unwrap().mapped_ipv4(),
+            Some(Ipv4Addr::new(192, 0, 2, 1))
+        );
+        assert_eq!(IPv6Address::new("2001:db8::1").unwrap().mapped_ipv4(), None);
+
+        assert!(IPv6Address::new("2001:2::1").unwrap().is_benchmarking());
+        assert!(!IPv6Address::new("2001:3::1").unwrap().is_benchmarking());
+
+        assert_eq!(
+            IPv6Address::new("2001:db8::1").unwrap().address_type(),
+            "Documentation"
+        );
+        assert_eq!(
+            IPv6Address::new("2001:2::1").unwrap().address_type(),
+            "Benchmarking"
+        );
+        assert_eq!(
+            IPv6Address::new("::ffff:192.0.2.1").unwrap().address_type(),
+            "IPv4-Mapped"
+        );
+    }
+
+    #[test]
+    fn test_multicast_scope() {
+        assert_eq!(IPv6Address::new("2001:db8::1").unwrap().multicast_scope(), None);
+        assert_eq!(
+            IPv6Address::new("ff02::1").unwrap().multicast_scope(),
+            Some(MulticastScope::LinkLocal)
+        );
+        assert_eq!(
+            IPv6Address::new("ff05::1").unwrap().multicast_scope(),
+            Some(MulticastScope::SiteLocal)
+        );
+        assert_eq!(
+            IPv6Address::new("ff0e::1").unwrap().multicast_scope(),
+            Some(MulticastScope::Global)
+        );
+        assert_eq!(
+            IPv6Address::new("ff06::1").unwrap().multicast_scope(),
+            Some(MulticastScope::Unassigned(6))
+        );
+    }
+
+    #[test]
+    fn test_multicast_flags() {
+        assert_eq!(IPv6Address::new("2001:db8::1").unwrap().multicast_flags(), None);
+
+        let well_known = IPv6Address::new("ff02::1").unwrap().multicast_flags().unwrap();
+        assert!(!well_known.transient);
+        assert!(!well_known.prefix_based);
+        assert!(!well_known.rendezvous);
+
+        // ff3e:: - transient, prefix-based (SSM-style)
+        let ssm = IPv6Address::new("ff3e::").unwrap().multicast_flags().unwrap();
+        assert!(ssm.transient);
+        assert!(ssm.prefix_based);
+        assert!(!ssm.rendezvous);
+
+        // ff7e:: - transient, prefix-based, embedded-RP (RFC 3956)
+        let embedded_rp = IPv6Address::new("ff7e::").unwrap().multicast_flags().unwrap();
+        assert!(embedded_rp.transient);
+        assert!(embedded_rp.prefix_based);
+        assert!(embedded_rp.rendezvous);
+    }
+
     #[test]
     fn test_network_parsing() {
         let net = IPv6Network::new("2001:db8::/32").unwrap();
@@ -389,6 +809,34 @@ mod tests {
         assert_eq!(net.network_address().compressed(), "2001:db8::");
     }
 
+    #[test]
+    fn test_network_parse_errors() {
+        assert!(matches!(
+            IPv6Network::new("2001:db8::"),
+            Err(Ipv6Error::MissingPrefixLength)
+        ));
+        assert!(matches!(
+            IPv6Network::new("2001:db8::garbage/32"),
+            Err(Ipv6Error::MalformedAddress(_))
+        ));
+        assert!(matches!(
+            IPv6Network::new("2001:db8::/200"),
+            Err(Ipv6Error::PrefixOutOfRange(_))
+        ));
+
+        match IPv6Network::new("2001:db8::1/64") {
+            Err(Ipv6Error::NotNetworkAddress {
+                prefix_len,
+                suggested,
+                ..
+            }) => {
+                assert_eq!(prefix_len, 64);
+                assert_eq!(suggested, "2001:db8::/64");
+            }
+            other => panic!("expected NotNetworkAddress, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_network_contains() {
         let net = IPv6Network::new("2001:db8::/32").unwrap();
@@ -403,4 +851,98 @@ mod tests {
         assert_eq!(subs.len(), 16);
         assert_eq!(subs[0].prefix_len(), 36);
     }
+
+    #[test]
+    fn test_hosts_single_address() {
+        let net = IPv6Network::new("2001:db8::1/128").unwrap();
+        let addrs: Vec<_> = net.hosts().collect();
+        assert_eq!(addrs.len(), 1);
+        assert_eq!(addrs[0].compressed(), "2001:db8::1");
+    }
+
+    #[test]
+    fn test_hosts_size_hint_and_fused() {
+        let net = IPv6Network::new("2001:db8::/126").unwrap();
+        let mut hosts = net.hosts();
+        assert_eq!(hosts.size_hint(), (4, Some(4)));
+        assert_eq!(hosts.clone().count(), 4);
+        for _ in 0..4 {
+            assert!(hosts.next().is_some());
+        }
+        assert_eq!(hosts.next(), None);
+        assert_eq!(hosts.next(), None);
+    }
+
+    #[test]
+    fn test_summarize_address_range() {
+        let start = IPv6Address::new("2001:db8::1").unwrap();
+        let end = IPv6Address::new("2001:db8::10").unwrap();
+        let blocks = summarize_address_range(&start, &end).unwrap();
+
+        assert_eq!(
+            blocks.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+            vec![
+                "2001:db8::1/128",
+                "2001:db8::2/127",
+                "2001:db8::4/126",
+                "2001:db8::8/125",
+                "2001:db8::10/128",
+            ]
+        );
+
+        let total: u128 = blocks.iter().map(|n| n.num_addresses()).sum();
+        assert_eq!(total, 16);
+    }
+
+    #[test]
+    fn test_summarize_single_network() {
+        let start = IPv6Address::new("2001:db8::").unwrap();
+        let end = IPv6Address::new("2001:db8:ffff:ffff:ffff:ffff:ffff:ffff").unwrap();
+        let blocks = summarize_address_range(&start, &end).unwrap();
+        assert_eq!(blocks, vec![IPv6Network::new("2001:db8::/32").unwrap()]);
+    }
+
+    #[test]
+    fn test_summarize_full_address_space() {
+        let start = IPv6Address::new("::").unwrap();
+        let end = IPv6Address::new("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff").unwrap();
+        let blocks = summarize_address_range(&start, &end).unwrap();
+        assert_eq!(blocks, vec![IPv6Network::new("::/0").unwrap()]);
+    }
+
+    #[test]
+    fn test_summarize_rejects_inverted_range() {
+        let start = IPv6Address::new("2001:db8::10").unwrap();
+        let end = IPv6Address::new("2001:db8::1").unwrap();
+        assert!(summarize_address_range(&start, &end).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_merges_siblings() {
+        let nets = [
+            IPv6Network::new("2001:db8::/33").unwrap(),
+            IPv6Network::new("2001:db8:8000::/33").unwrap(),
+        ];
+        let aggregated = IPv6Network::aggregate(&nets);
+        assert_eq!(aggregated, vec![IPv6Network::new("2001:db8::/32").unwrap()]);
+    }
+
+    #[test]
+    fn test_aggregate_absorbs_contained() {
+        let nets = [
+            IPv6Network::new("2001:db8::/32").unwrap(),
+            IPv6Network::new("2001:db8::/48").unwrap(),
+        ];
+        let aggregated = IPv6Network::aggregate(&nets);
+        assert_eq!(aggregated, vec![IPv6Network::new("2001:db8::/32").unwrap()]);
+    }
+
+    #[test]
+    fn test_aggregate_keeps_disjoint() {
+        let nets = [
+            IPv6Network::new("2001:db8::/32").unwrap(),
+            IPv6Network::new("2001:dba::/32").unwrap(),
+        ];
+        assert_eq!(IPv6Network::aggregate(&nets).len(), 2);
+    }
 }
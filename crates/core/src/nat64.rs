@@ -0,0 +1,107 @@
+//! NAT64 address synthesis and translation (RFC 6052).
+//!
+//! Embeds an IPv4 address into an IPv6 address under one of the prefix
+//! lengths defined by RFC 6052 — including the well-known `64:ff9b::/96`
+//! prefix — and extracts it back out.
+
+use crate::{IPv6Address, IPv6Network, Ipv6Error, Result};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Prefix lengths permitted by RFC 6052 for NAT64 address synthesis.
+const ALLOWED_PREFIX_LENGTHS: [u8; 6] = [32, 40, 48, 56, 64, 96];
+
+/// Embed `v4` into `prefix` per RFC 6052 to synthesize the corresponding
+/// IPv6 address, e.g. under the well-known `64:ff9b::/96` prefix.
+pub fn embed_ipv4(prefix: &IPv6Network, v4: Ipv4Addr) -> Result<IPv6Address> {
+    let prefix_len = prefix.prefix_len();
+    if !ALLOWED_PREFIX_LENGTHS.contains(&prefix_len) {
+        return Err(Ipv6Error::InvalidPrefix(format!(
+            "NAT64 prefix length must be one of 32/40/48/56/64/96, got /{}",
+            prefix_len
+        )));
+    }
+
+    let prefix_bytes = prefix.network_address().inner().octets();
+    let v4_bytes = v4.octets();
+    let prefix_octets = (prefix_len / 8) as usize;
+
+    let mut out = [0u8; 16];
+    out[..prefix_octets].copy_from_slice(&prefix_bytes[..prefix_octets]);
+
+    if prefix_len == 96 {
+        out[12..16].copy_from_slice(&v4_bytes);
+    } else {
+        for (v4_idx, out_idx) in v4_octet_positions(prefix_octets).enumerate() {
+            out[out_idx] = v4_bytes[v4_idx];
+        }
+    }
+
+    Ok(IPv6Address {
+        addr: Ipv6Addr::from(out),
+        zone_id: None,
+    })
+}
+
+/// Extract the embedded IPv4 address from `addr`, assuming it was
+/// synthesized under a prefix of length `prefix_len` per RFC 6052. Returns
+/// `None` if `prefix_len` isn't one of the allowed NAT64 prefix lengths.
+pub fn extract_ipv4(addr: &IPv6Address, prefix_len: u8) -> Option<Ipv4Addr> {
+    if !ALLOWED_PREFIX_LENGTHS.contains(&prefix_len) {
+        return None;
+    }
+
+    let bytes = addr.inner().octets();
+    let prefix_octets = (prefix_len / 8) as usize;
+    let mut v4 = [0u8; 4];
+
+    if prefix_len == 96 {
+        v4.copy_from_slice(&bytes[12..16]);
+    } else {
+        for (v4_idx, in_idx) in v4_octet_positions(prefix_octets).enumerate() {
+            v4[v4_idx] = bytes[in_idx];
+        }
+    }
+
+    Some(Ipv4Addr::from(v4))
+}
+
+/// The four byte positions (within a 16-byte address) holding the embedded
+/// IPv4 address for a non-/96 NAT64 prefix, skipping byte 8 — the reserved
+/// "u" octet that must be zero (RFC 6052 section 2.2).
+fn v4_octet_positions(prefix_octets: usize) -> impl Iterator<Item = usize> {
+    (prefix_octets..16).filter(|&idx| idx != 8).take(4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embed_well_known_96() {
+        let prefix = IPv6Network::new("64:ff9b::/96").unwrap();
+        let addr = embed_ipv4(&prefix, Ipv4Addr::new(192, 0, 2, 33)).unwrap();
+        assert_eq!(addr.compressed(), "64:ff9b::c000:221");
+    }
+
+    #[test]
+    fn test_embed_and_extract_roundtrip_all_prefix_lengths() {
+        let v4 = Ipv4Addr::new(192, 0, 2, 33);
+        for &prefix_len in &[32u8, 40, 48, 56, 64, 96] {
+            let prefix = IPv6Network::new(&format!("2001:db8::/{}", prefix_len)).unwrap();
+            let addr = embed_ipv4(&prefix, v4).unwrap();
+            assert_eq!(extract_ipv4(&addr, prefix_len), Some(v4));
+        }
+    }
+
+    #[test]
+    fn test_embed_rejects_bad_prefix_length() {
+        let prefix = IPv6Network::new("2001:db8::/36").unwrap();
+        assert!(embed_ipv4(&prefix, Ipv4Addr::new(192, 0, 2, 33)).is_err());
+    }
+
+    #[test]
+    fn test_extract_rejects_bad_prefix_length() {
+        let addr = IPv6Address::new("64:ff9b::c000:221").unwrap();
+        assert_eq!(extract_ipv4(&addr, 36), None);
+    }
+}
@@ -0,0 +1,259 @@
+//! IPv6 fixed packet header codec (RFC 8200 section 3).
+//!
+//! Parses and serializes the 40-byte fixed header on top of a `&[u8]` /
+//! `&mut [u8]` buffer, converting the embedded 16-byte source/destination
+//! addresses directly into [`IPv6Address`] via `Ipv6Addr::from([u8; 16])`.
+
+use crate::{IPv6Address, Ipv6Error, Result};
+use std::net::Ipv6Addr;
+
+/// Size in bytes of the fixed IPv6 header.
+pub const HEADER_LEN: usize = 40;
+
+/// The only valid value of the 4-bit version field.
+pub const VERSION: u8 = 6;
+
+/// Minimum MTU every IPv6 link must support (RFC 8200 section 5).
+pub const MIN_MTU: usize = 1280;
+
+/// A parsed/to-be-serialized IPv6 fixed header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    traffic_class: u8,
+    flow_label: u32,
+    payload_length: u16,
+    next_header: u8,
+    hop_limit: u8,
+    source: IPv6Address,
+    destination: IPv6Address,
+}
+
+impl Header {
+    /// Build a header. `flow_label` must fit in 20 bits.
+    pub fn new(
+        traffic_class: u8,
+        flow_label: u32,
+        payload_length: u16,
+        next_header: u8,
+        hop_limit: u8,
+        source: IPv6Address,
+        destination: IPv6Address,
+    ) -> Result<Self> {
+        if flow_label > 0x0f_ffff {
+            return Err(Ipv6Error::MalformedHeader(format!(
+                "flow label {} exceeds 20 bits",
+                flow_label
+            )));
+        }
+
+        Ok(Self {
+            traffic_class,
+            flow_label,
+            payload_length,
+            next_header,
+            hop_limit,
+            source,
+            destination,
+        })
+    }
+
+    /// Always 6 — the only version this codec understands.
+    pub fn version(&self) -> u8 {
+        VERSION
+    }
+
+    pub fn traffic_class(&self) -> u8 {
+        self.traffic_class
+    }
+
+    /// The 20-bit flow label.
+    pub fn flow_label(&self) -> u32 {
+        self.flow_label
+    }
+
+    pub fn payload_length(&self) -> u16 {
+        self.payload_length
+    }
+
+    pub fn next_header(&self) -> u8 {
+        self.next_header
+    }
+
+    pub fn hop_limit(&self) -> u8 {
+        self.hop_limit
+    }
+
+    pub fn source(&self) -> &IPv6Address {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &IPv6Address {
+        &self.destination
+    }
+
+    /// Parse a fixed header from the front of `buf`. `buf` may also contain
+    /// the payload that follows; only the leading 40 bytes are consumed,
+    /// but `payload_length` is checked against what remains.
+    pub fn parse(buf: &[u8]) -> Result<Self> {
+        if buf.len() < HEADER_LEN {
+            return Err(Ipv6Error::TruncatedHeader(buf.len()));
+        }
+
+        let version = buf[0] >> 4;
+        if version != VERSION {
+            return Err(Ipv6Error::MalformedHeader(format!(
+                "expected version {}, got {}",
+                VERSION, version
+            )));
+        }
+
+        let traffic_class = ((buf[0] & 0x0f) << 4) | (buf[1] >> 4);
+        let flow_label =
+            ((buf[1] as u32 & 0x0f) << 16) | ((buf[2] as u32) << 8) | buf[3] as u32;
+        let payload_length = u16::from_be_bytes([buf[4], buf[5]]);
+        let next_header = buf[6];
+        let hop_limit = buf[7];
+
+        let mut source_octets = [0u8; 16];
+        source_octets.copy_from_slice(&buf[8..24]);
+        let mut destination_octets = [0u8; 16];
+        destination_octets.copy_from_slice(&buf[24..40]);
+
+        let source = IPv6Address {
+            addr: Ipv6Addr::from(source_octets),
+            zone_id: None,
+        };
+        let destination = IPv6Address {
+            addr: Ipv6Addr::from(destination_octets),
+            zone_id: None,
+        };
+
+        if buf.len() - HEADER_LEN < payload_length as usize {
+            return Err(Ipv6Error::MalformedHeader(format!(
+                "payload length {} exceeds the {} bytes available after the header",
+                payload_length,
+                buf.len() - HEADER_LEN
+            )));
+        }
+
+        Ok(Self {
+            traffic_class,
+            flow_label,
+            payload_length,
+            next_header,
+            hop_limit,
+            source,
+            destination,
+        })
+    }
+
+    /// Serialize this header into the first 40 bytes of `buf`.
+    ///
+    /// `buf` must be at least `HEADER_LEN + self.payload_length()` bytes —
+    /// large enough to also hold the payload the header declares — even
+    /// though only the header itself is written.
+    pub fn write(&self, buf: &mut [u8]) -> Result<()> {
+        if buf.len() < HEADER_LEN + self.payload_length as usize {
+            return Err(Ipv6Error::MalformedHeader(format!(
+                "buffer of {} bytes is too small for a header plus {}-byte payload",
+                buf.len(),
+                self.payload_length
+            )));
+        }
+
+        buf[0] = (VERSION << 4) | (self.traffic_class >> 4);
+        buf[1] = (self.traffic_class << 4) | ((self.flow_label >> 16) as u8 & 0x0f);
+        buf[2] = (self.flow_label >> 8) as u8;
+        buf[3] = self.flow_label as u8;
+        buf[4..6].copy_from_slice(&self.payload_length.to_be_bytes());
+        buf[6] = self.next_header;
+        buf[7] = self.hop_limit;
+        buf[8..24].copy_from_slice(&self.source.inner().octets());
+        buf[24..40].copy_from_slice(&self.destination.inner().octets());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_header() -> Header {
+        Header::new(
+            0x12,
+            0xabcde,
+            64,
+            6,
+            64,
+            IPv6Address::new("2001:db8::1").unwrap(),
+            IPv6Address::new("2001:db8::2").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let header = sample_header();
+        let mut buf = [0u8; HEADER_LEN + 64];
+        header.write(&mut buf).unwrap();
+
+        let parsed = Header::parse(&buf).unwrap();
+        assert_eq!(parsed, header);
+        assert_eq!(parsed.version(), 6);
+        assert_eq!(parsed.source().compressed(), "2001:db8::1");
+        assert_eq!(parsed.destination().compressed(), "2001:db8::2");
+    }
+
+    #[test]
+    fn test_parse_truncated() {
+        let buf = [0u8; HEADER_LEN - 1];
+        assert!(matches!(
+            Header::parse(&buf),
+            Err(Ipv6Error::TruncatedHeader(39))
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_version() {
+        let mut buf = [0u8; HEADER_LEN];
+        buf[0] = 0x40; // version 4
+        assert!(matches!(
+            Header::parse(&buf),
+            Err(Ipv6Error::MalformedHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_inconsistent_payload_length() {
+        let header = sample_header();
+        let mut buf = [0u8; HEADER_LEN + 64];
+        header.write(&mut buf).unwrap();
+        let truncated_payload = &buf[..HEADER_LEN + 10];
+        assert!(matches!(
+            Header::parse(truncated_payload),
+            Err(Ipv6Error::MalformedHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_rejects_oversized_flow_label() {
+        assert!(Header::new(
+            0,
+            1 << 20,
+            0,
+            6,
+            64,
+            IPv6Address::new("::1").unwrap(),
+            IPv6Address::new("::1").unwrap(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_write_rejects_undersized_buffer() {
+        let header = sample_header();
+        let mut buf = [0u8; HEADER_LEN];
+        assert!(header.write(&mut buf).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Longest-prefix-match routing trie over `IPv6Network`.
+
+use ipv6_only_core::{IPv6Address, IPv6Network};
+
+struct Node<T> {
+    entry: Option<(IPv6Network, T)>,
+    children: [Option<Box<Node<T>>>; 2],
+}
+
+impl<T> Node<T> {
+    fn new() -> Self {
+        Self {
+            entry: None,
+            children: [None, None],
+        }
+    }
+}
+
+/// A binary trie over the 128-bit IPv6 address space, keyed on `IPv6Network`
+/// prefixes, supporting longest-prefix-match lookups the way a router
+/// chooses the most specific matching route for a destination address.
+pub struct IPv6PrefixTrie<T> {
+    root: Node<T>,
+    len: usize,
+}
+
+impl<T> IPv6PrefixTrie<T> {
+    /// Create an empty trie.
+    pub fn new() -> Self {
+        Self {
+            root: Node::new(),
+            len: 0,
+        }
+    }
+
+    /// Number of stored prefixes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the trie holds no prefixes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert `network` with `value`, walking/creating nodes down to
+    /// `network.prefix_len()` bits. Returns the previous value for this
+    /// exact prefix, if any.
+    pub fn insert(&mut self, network: IPv6Network, value: T) -> Option<T> {
+        let addr = network.network_address().to_u128();
+        let mut node = &mut self.root;
+        for i in 0..network.prefix_len() {
+            let bit = ((addr >> (127 - i)) & 1) as usize;
+            node = node.children[bit].get_or_insert_with(|| Box::new(Node::new()));
+        }
+        let previous = node.entry.replace((network, value));
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous.map(|(_, value)| value)
+    }
+
+    /// Remove the exact `network` entry, returning its value if present.
+    /// Sibling/ancestor prefixes are left untouched.
+    pub fn remove(&mut self, network: &IPv6Network) -> Option<T> {
+        let addr = network.network_address().to_u128();
+        let mut node = &mut self.root;
+        for i in 0..network.prefix_len() {
+            let bit = ((addr >> (127 - i)) & 1) as usize;
+            node = node.children[bit].as_deref_mut()?;
+        }
+        let removed = node.entry.take();
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed.map(|(_, value)| value)
+    }
+
+    /// Find the value of the longest stored prefix containing `address`.
+    ///
+    /// Walks bit-by-bit from the root as far as `address` matches, and among
+    /// every node passed through that carries an entry, keeps the deepest
+    /// one whose network actually `contains` the address (rather than just
+    /// the deepest node reached) — a `/0` default route always qualifies.
+    pub fn lookup(&self, address: &IPv6Address) -> Option<&T> {
+        let addr = address.to_u128();
+        let mut node = &self.root;
+        let mut best = node
+            .entry
+            .as_ref()
+            .filter(|(net, _)| net.contains(address));
+
+        for i in 0..128u8 {
+            let bit = ((addr >> (127 - i)) & 1) as usize;
+            let Some(child) = node.children[bit].as_deref() else {
+                break;
+            };
+            node = child;
+            if let Some(entry) = node.entry.as_ref() {
+                if entry.0.contains(address) {
+                    best = Some(entry);
+                }
+            }
+        }
+
+        best.map(|(_, value)| value)
+    }
+
+    /// Iterate over every stored `(network, value)` entry, in unspecified order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            stack: vec![&self.root],
+        }
+    }
+}
+
+impl<T> Default for IPv6PrefixTrie<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the `(network, value)` entries of an [`IPv6PrefixTrie`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (&'a IPv6Network, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            for child in node.children.iter().flatten() {
+                self.stack.push(child);
+            }
+            if let Some((net, value)) = &node.entry {
+                return Some((net, value));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_prefix_match() {
+        let mut trie = IPv6PrefixTrie::new();
+        trie.insert(IPv6Network::new("2000::/3").unwrap(), "global");
+        trie.insert(IPv6Network::new("2001:db8::/32").unwrap(), "docs");
+        trie.insert(IPv6Network::new("2001:db8:1::/48").unwrap(), "docs-site-1");
+
+        assert_eq!(
+            trie.lookup(&IPv6Address::new("2001:db8:1::1").unwrap()),
+            Some(&"docs-site-1")
+        );
+        assert_eq!(
+            trie.lookup(&IPv6Address::new("2001:db8:2::1").unwrap()),
+            Some(&"docs")
+        );
+        assert_eq!(
+            trie.lookup(&IPv6Address::new("2002::1").unwrap()),
+            Some(&"global")
+        );
+    }
+
+    #[test]
+    fn test_default_route() {
+        let mut trie = IPv6PrefixTrie::new();
+        trie.insert(IPv6Network::new("::/0").unwrap(), "default");
+
+        assert_eq!(
+            trie.lookup(&IPv6Address::new("fe80::1").unwrap()),
+            Some(&"default")
+        );
+        assert_eq!(
+            trie.lookup(&IPv6Address::new("::").unwrap()),
+            Some(&"default")
+        );
+    }
+
+    #[test]
+    fn test_no_match() {
+        let mut trie: IPv6PrefixTrie<&str> = IPv6PrefixTrie::new();
+        trie.insert(IPv6Network::new("2001:db8::/32").unwrap(), "docs");
+        assert_eq!(trie.lookup(&IPv6Address::new("fe80::1").unwrap()), None);
+    }
+
+    #[test]
+    fn test_insert_overwrite_and_remove() {
+        let mut trie = IPv6PrefixTrie::new();
+        let net = IPv6Network::new("2001:db8::/32").unwrap();
+
+        assert_eq!(trie.insert(net.clone(), "first"), None);
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.insert(net.clone(), "second"), Some("first"));
+        assert_eq!(trie.len(), 1);
+
+        assert_eq!(trie.remove(&net), Some("second"));
+        assert_eq!(trie.len(), 0);
+        assert!(trie.is_empty());
+        assert_eq!(trie.lookup(&IPv6Address::new("2001:db8::1").unwrap()), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut trie = IPv6PrefixTrie::new();
+        trie.insert(IPv6Network::new("2001:db8::/32").unwrap(), 1);
+        trie.insert(IPv6Network::new("2001:db9::/32").unwrap(), 2);
+
+        let mut values: Vec<_> = trie.iter().map(|(_, v)| *v).collect();
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 2]);
+    }
+}
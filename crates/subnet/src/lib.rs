@@ -1,6 +1,6 @@
 //! IPv6 subnet calculator and network planning utilities.
 
-use ipv6_only_core::{IPv6Network, Ipv6Error, Result};
+use ipv6_only_core::{IPv6AddressRange, IPv6Network, Ipv6Error, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -112,20 +112,62 @@ impl IPv6SubnetCalculator {
     }
 
     /// Check if an address is within this network.
-    pub fn contains_address(&self, address: &str) -> bool {
+    pub fn contains_address(&self, address: &str) -> Result<bool> {
         use ipv6_only_core::IPv6Address;
-        match IPv6Address::new(address) {
-            Ok(addr) => self.network.contains(&addr),
-            Err(_) => false,
-        }
+        let addr = IPv6Address::new(address)?;
+        Ok(self.network.contains(&addr))
     }
 
     /// Check if this network overlaps with another.
-    pub fn overlaps_with(&self, other_network: &str) -> bool {
-        match IPv6Network::new(other_network) {
-            Ok(other) => self.network.overlaps(&other),
-            Err(_) => false,
+    pub fn overlaps_with(&self, other_network: &str) -> Result<bool> {
+        let other = IPv6Network::new(other_network)?;
+        Ok(self.network.overlaps(&other))
+    }
+
+    /// Iterate over every address in the network.
+    pub fn hosts(&self) -> IPv6AddressRange {
+        self.network.hosts()
+    }
+
+    /// Subtract a contained network from this one, returning the minimal set
+    /// of disjoint CIDR blocks covering the remainder.
+    ///
+    /// Returns an empty list if `other` equals this network, and this network
+    /// unchanged if `other` is not contained within it. Returns an error if
+    /// `other` has a shorter prefix than this network, whether or not the two
+    /// overlap.
+    pub fn exclude(&self, other: &str) -> Result<Vec<SubnetInfo>> {
+        let other_net = IPv6Network::new(other)?;
+        let parent = &self.network;
+
+        if other_net.prefix_len() < parent.prefix_len() {
+            return Err(Ipv6Error::DivisionError(format!(
+                "Excluded network /{} is larger than the parent /{}",
+                other_net.prefix_len(),
+                parent.prefix_len()
+            )));
+        }
+
+        if other_net == *parent {
+            return Ok(Vec::new());
+        }
+
+        if !parent.contains(&other_net.network_address()) {
+            return Ok(vec![SubnetInfo::from_network(parent)]);
         }
+
+        let mut remainder = Vec::new();
+        let mut current = other_net;
+        while current.prefix_len() > parent.prefix_len() {
+            let sibling_bit = 1u128 << (128 - current.prefix_len());
+            let sibling_addr = current.network_address().to_u128() ^ sibling_bit;
+            let sibling = IPv6Network::from_u128(sibling_addr, current.prefix_len())?;
+            remainder.push(sibling);
+            current = current.supernet(1)?;
+        }
+
+        remainder.sort_by_key(|net| net.network_address().to_u128());
+        Ok(remainder.iter().map(SubnetInfo::from_network).collect())
     }
 
     /// Recommend subnet allocation based on department sizes.
@@ -169,7 +211,7 @@ impl IPv6SubnetCalculator {
 
             let dept_subnets: Vec<SubnetInfo> = all_subnets[current_index..current_index + count]
                 .iter()
-                .map(|net| SubnetInfo::from_network(net))
+                .map(SubnetInfo::from_network)
                 .collect();
 
             allocation.insert(dept_name.clone(), dept_subnets);
@@ -178,6 +220,100 @@ impl IPv6SubnetCalculator {
 
         Ok(allocation)
     }
+
+    /// Recommend a VLSM subnet allocation sized to each department's required
+    /// host/subnet capacity, instead of handing out equal-sized blocks.
+    ///
+    /// Departments are assigned the smallest prefix that fits their capacity
+    /// and packed contiguously using first-fit-decreasing (largest capacity
+    /// first) to minimize fragmentation. A capacity larger than the parent
+    /// network is clamped to the parent's prefix length, so that department
+    /// is given the whole parent block even though it is smaller than the
+    /// capacity asked for.
+    pub fn recommend_allocation_vlsm(
+        total_prefix: &str,
+        department_capacities: &HashMap<String, u128>,
+    ) -> Result<HashMap<String, SubnetInfo>> {
+        let network = IPv6Network::new(total_prefix)?;
+        let parent_prefix = network.prefix_len();
+        let parent_start = network.network_address().to_u128();
+        let parent_end = network.broadcast_address().to_u128();
+
+        let mut sorted_depts: Vec<_> = department_capacities.iter().collect();
+        sorted_depts.sort_by(|(name_a, cap_a), (name_b, cap_b)| {
+            cap_b.cmp(cap_a).then_with(|| name_a.cmp(name_b))
+        });
+
+        let mut allocation = HashMap::new();
+        let mut cursor = parent_start;
+        let mut exhausted = false;
+
+        for (dept_name, capacity) in sorted_depts {
+            if exhausted {
+                return Err(Ipv6Error::DivisionError(format!(
+                    "Not enough address space for department {}",
+                    dept_name
+                )));
+            }
+
+            let host_bits = if *capacity <= 1 {
+                0
+            } else {
+                (*capacity as f64).log2().ceil() as u8
+            };
+            let needed_prefix = (128 - host_bits).max(parent_prefix);
+
+            // `1u128 << (128 - needed_prefix)` overflows when needed_prefix is
+            // 0 (a department needs the whole address space), so that case is
+            // handled separately instead of as a block of size 2^128.
+            if needed_prefix == 0 {
+                if cursor != parent_start || parent_end != u128::MAX {
+                    return Err(Ipv6Error::DivisionError(format!(
+                        "Not enough address space for department {}",
+                        dept_name
+                    )));
+                }
+                let subnet = IPv6Network::from_u128(cursor, 0)?;
+                allocation.insert(dept_name.clone(), SubnetInfo::from_network(&subnet));
+                exhausted = true;
+                continue;
+            }
+
+            let block_size = 1u128 << (128 - needed_prefix);
+            let misalignment = cursor % block_size;
+            if misalignment != 0 {
+                cursor += block_size - misalignment;
+            }
+
+            if cursor + block_size - 1 > parent_end {
+                return Err(Ipv6Error::DivisionError(format!(
+                    "Not enough address space for department {}",
+                    dept_name
+                )));
+            }
+
+            let subnet = IPv6Network::from_u128(cursor, needed_prefix)?;
+            allocation.insert(dept_name.clone(), SubnetInfo::from_network(&subnet));
+            cursor += block_size;
+        }
+
+        Ok(allocation)
+    }
+}
+
+/// Collapse a set of networks into the minimal equivalent covering set,
+/// merging adjacent siblings and absorbing networks already covered by
+/// another entry in the set. The inverse of `divide_by_prefix`.
+pub fn aggregate_networks(networks: &[&str]) -> Result<Vec<SubnetInfo>> {
+    let nets: Vec<IPv6Network> = networks
+        .iter()
+        .map(|s| IPv6Network::new(s))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(IPv6Network::aggregate(&nets)
+        .iter()
+        .map(SubnetInfo::from_network)
+        .collect())
 }
 
 #[cfg(test)]
@@ -203,8 +339,17 @@ mod tests {
     #[test]
     fn test_contains_address() {
         let calc = IPv6SubnetCalculator::new("2001:db8::/32").unwrap();
-        assert!(calc.contains_address("2001:db8::1"));
-        assert!(!calc.contains_address("2001:db9::1"));
+        assert!(calc.contains_address("2001:db8::1").unwrap());
+        assert!(!calc.contains_address("2001:db9::1").unwrap());
+        assert!(calc.contains_address("not-an-address").is_err());
+    }
+
+    #[test]
+    fn test_overlaps_with() {
+        let calc = IPv6SubnetCalculator::new("2001:db8::/32").unwrap();
+        assert!(calc.overlaps_with("2001:db8::/48").unwrap());
+        assert!(!calc.overlaps_with("2001:db9::/32").unwrap());
+        assert!(calc.overlaps_with("not-a-network").is_err());
     }
 
     #[test]
@@ -213,4 +358,115 @@ mod tests {
         let supernet = calc.get_supernet(24).unwrap();
         assert_eq!(supernet.prefix_length, 24);
     }
+
+    #[test]
+    fn test_exclude() {
+        let calc = IPv6SubnetCalculator::new("2001:db8::/32").unwrap();
+        let remainder = calc.exclude("2001:db8::/48").unwrap();
+        assert_eq!(remainder.len(), 16);
+        assert_eq!(remainder[0].prefix_length, 48);
+
+        let unchanged = calc.exclude("2001:db9::/48").unwrap();
+        assert_eq!(unchanged.len(), 1);
+        assert_eq!(unchanged[0].network, "2001:db8::/32");
+
+        let empty = calc.exclude("2001:db8::/32").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_hosts() {
+        let calc = IPv6SubnetCalculator::new("2001:db8::/126").unwrap();
+        let addrs: Vec<_> = calc.hosts().take(2).collect();
+        assert_eq!(addrs.len(), 2);
+        assert_eq!(addrs[0].compressed(), "2001:db8::");
+        assert_eq!(addrs[1].compressed(), "2001:db8::1");
+    }
+
+    #[test]
+    fn test_aggregate_merges_siblings() {
+        let aggregated =
+            aggregate_networks(&["2001:db8::/33", "2001:db8:8000::/33"]).unwrap();
+        assert_eq!(aggregated.len(), 1);
+        assert_eq!(aggregated[0].network, "2001:db8::/32");
+    }
+
+    #[test]
+    fn test_aggregate_absorbs_contained() {
+        let aggregated = aggregate_networks(&["2001:db8::/32", "2001:db8::/48"]).unwrap();
+        assert_eq!(aggregated.len(), 1);
+        assert_eq!(aggregated[0].network, "2001:db8::/32");
+    }
+
+    #[test]
+    fn test_recommend_allocation_vlsm() {
+        let mut capacities = HashMap::new();
+        capacities.insert("engineering".to_string(), 500u128);
+        capacities.insert("sales".to_string(), 50u128);
+
+        let allocation =
+            IPv6SubnetCalculator::recommend_allocation_vlsm("2001:db8::/56", &capacities)
+                .unwrap();
+
+        let engineering = &allocation["engineering"];
+        assert_eq!(engineering.prefix_length, 119); // 512 addresses, fits 500 hosts
+        assert_eq!(engineering.network, "2001:db8::/119");
+
+        let sales = &allocation["sales"];
+        assert_eq!(sales.prefix_length, 122); // 64 addresses, fits 50 hosts
+        assert_eq!(sales.network, "2001:db8::200/122");
+    }
+
+    #[test]
+    fn test_recommend_allocation_vlsm_exhausted() {
+        let mut capacities = HashMap::new();
+        capacities.insert("a".to_string(), 1u128);
+        capacities.insert("b".to_string(), 1u128);
+        capacities.insert("c".to_string(), 1u128);
+
+        let result = IPv6SubnetCalculator::recommend_allocation_vlsm("2001:db8::/127", &capacities);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_recommend_allocation_vlsm_full_address_space() {
+        let mut capacities = HashMap::new();
+        capacities.insert("engineering".to_string(), 500u128);
+        capacities.insert("sales".to_string(), 50u128);
+
+        let allocation = IPv6SubnetCalculator::recommend_allocation_vlsm("::/0", &capacities)
+            .unwrap();
+
+        assert_eq!(allocation["engineering"].prefix_length, 119);
+        assert_eq!(allocation["sales"].prefix_length, 122);
+    }
+
+    #[test]
+    fn test_recommend_allocation_vlsm_whole_address_space_capacity() {
+        // A capacity this large needs every address in ::/0 (needed_prefix
+        // would compute to 0), which used to overflow `1u128 << 128`.
+        let mut capacities = HashMap::new();
+        capacities.insert("huge".to_string(), u128::MAX);
+
+        let allocation = IPv6SubnetCalculator::recommend_allocation_vlsm("::/0", &capacities)
+            .unwrap();
+        assert_eq!(allocation["huge"].prefix_length, 0);
+        assert_eq!(allocation["huge"].network, "::/0");
+    }
+
+    #[test]
+    fn test_recommend_allocation_vlsm_whole_address_space_capacity_with_others_fails() {
+        let mut capacities = HashMap::new();
+        capacities.insert("huge".to_string(), u128::MAX);
+        capacities.insert("other".to_string(), 1u128);
+
+        let result = IPv6SubnetCalculator::recommend_allocation_vlsm("::/0", &capacities);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_aggregate_keeps_disjoint() {
+        let aggregated = aggregate_networks(&["2001:db8::/32", "2001:dba::/32"]).unwrap();
+        assert_eq!(aggregated.len(), 2);
+    }
 }
@@ -3,6 +3,7 @@
 use ipv6_only_core::{IPv6Address, IPv6Network, Ipv6Error, Result};
 use rand::Rng;
 use std::net::Ipv6Addr;
+use std::str::FromStr;
 
 /// Compress an IPv6 address to its shortest form.
 pub fn compress_address(address: &str) -> Result<String> {
@@ -124,6 +125,11 @@ pub fn generate_unique_local(
 }
 
 /// Generate a random IPv6 address with given prefix.
+///
+/// Unlike [`IPv6Network::new`], the address half of `prefix` need not already
+/// be the canonical network address — any host bits present are masked off
+/// before randomizing, mirroring how a caller would expect "address + prefix
+/// length" input to behave here.
 pub fn generate_random_ipv6(prefix: &str) -> Result<String> {
     let prefix_with_len = if prefix.contains('/') {
         prefix.to_string()
@@ -131,7 +137,15 @@ pub fn generate_random_ipv6(prefix: &str) -> Result<String> {
         format!("{}/64", prefix)
     };
 
-    let network = IPv6Network::new(&prefix_with_len)?;
+    let (addr_str, prefix_str) = prefix_with_len
+        .split_once('/')
+        .ok_or(Ipv6Error::MissingPrefixLength)?;
+    let prefix_len: u8 = prefix_str
+        .parse()
+        .map_err(|_| Ipv6Error::PrefixOutOfRange(prefix_str.to_string()))?;
+    let addr = Ipv6Addr::from_str(addr_str)
+        .map_err(|_| Ipv6Error::MalformedAddress(addr_str.to_string()))?;
+    let network = IPv6Network::from_u128(u128::from(addr), prefix_len)?;
     let prefix_len = network.prefix_len();
 
     let host_bits = 128 - prefix_len;
@@ -248,17 +262,6 @@ pub fn validate_ipv6(address: &str, allow_zone: bool) -> (bool, Option<String>)
 
 /// Validate IPv6 network and return error message if invalid.
 pub fn validate_ipv6_network(network: &str) -> (bool, Option<String>) {
-    if network.is_empty() {
-        return (false, Some("Network cannot be empty".to_string()));
-    }
-
-    if !network.contains('/') {
-        return (
-            false,
-            Some("Network must include prefix length (e.g., 2001:db8::/32)".to_string()),
-        );
-    }
-
     match IPv6Network::new(network) {
         Ok(_) => (true, None),
         Err(e) => (false, Some(e.to_string())),
@@ -299,4 +302,30 @@ mod tests {
         assert!(!valid);
         assert!(err.is_some());
     }
+
+    #[test]
+    fn test_generate_random_ipv6_masks_non_canonical_prefix() {
+        // "2001:db8::5/64" has host bits set; generate_random_ipv6 should
+        // mask down to the network rather than rejecting it like
+        // IPv6Network::new now does.
+        let addr = generate_random_ipv6("2001:db8::5/64").unwrap();
+        assert!(addr.starts_with("2001:db8::") || addr.starts_with("2001:0db8:"));
+        let parsed = IPv6Address::new(&addr).unwrap();
+        let network = IPv6Network::new("2001:db8::/64").unwrap();
+        assert!(network.contains(&parsed));
+    }
+
+    #[test]
+    fn test_validate_ipv6_network_reports_specific_failure() {
+        let (valid, err) = validate_ipv6_network("2001:db8::1/64");
+        assert!(!valid);
+        assert!(matches!(
+            err,
+            Some(ref msg) if msg.contains("2001:db8::/64")
+        ));
+
+        let (valid, err) = validate_ipv6_network("2001:db8::1");
+        assert!(!valid);
+        assert!(err.is_some());
+    }
 }
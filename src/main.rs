@@ -4,7 +4,7 @@
 
 use clap::{Parser, Subcommand};
 use ipv6_only_core::{IPv6Address, IPv6Network};
-use ipv6_only_subnet::IPv6SubnetCalculator;
+use ipv6_only_subnet::{aggregate_networks, IPv6SubnetCalculator};
 use ipv6_only_utils::{
     compress_address, expand_address, generate_link_local, generate_random_ipv6,
     generate_unique_local, mac_to_ipv6_link_local, reverse_pointer, validate_ipv6,
@@ -52,6 +52,18 @@ enum Commands {
         /// Check if address is in network
         #[arg(short, long)]
         contains: Option<String>,
+
+        /// Exclude a contained subnet, printing the minimal covering remainder
+        #[arg(short = 'e', long)]
+        exclude: Option<String>,
+
+        /// Print the first addresses in the network
+        #[arg(long)]
+        hosts: bool,
+
+        /// Limit the number of addresses printed with --hosts
+        #[arg(long, default_value = "10")]
+        limit: usize,
     },
 
     /// Validate IPv6 addresses or networks
@@ -118,6 +130,13 @@ enum Commands {
         /// IPv6 address to analyze
         address: String,
     },
+
+    /// Aggregate multiple networks into their minimal covering set
+    Aggregate {
+        /// IPv6 networks in CIDR notation to aggregate
+        #[arg(required = true)]
+        networks: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -169,10 +188,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             prefix,
             supernet,
             contains,
+            exclude,
+            hosts,
+            limit,
         } => {
             let calc = IPv6SubnetCalculator::new(&network)?;
 
-            if info || (divide.is_none() && prefix.is_none() && supernet.is_none() && contains.is_none()) {
+            if info
+                || (divide.is_none()
+                    && prefix.is_none()
+                    && supernet.is_none()
+                    && contains.is_none()
+                    && exclude.is_none()
+                    && !hosts)
+            {
                 let net_info = calc.get_info();
                 if cli.format == "json" {
                     println!("{}", serde_json::to_string_pretty(&net_info)?);
@@ -218,9 +247,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             if let Some(addr) = contains {
-                let in_network = calc.contains_address(&addr);
+                let in_network = calc.contains_address(&addr)?;
                 println!("{} is {} {}", addr, if in_network { "in" } else { "not in" }, network);
             }
+
+            if let Some(exclude_net) = exclude {
+                let remainder = calc.exclude(&exclude_net)?;
+                println!("\nRemaining after excluding {}:", exclude_net);
+                for subnet in &remainder {
+                    println!("  {}", subnet.network);
+                }
+            }
+
+            if hosts {
+                println!("\nFirst {} addresses:", limit);
+                for addr in calc.hosts().take(limit) {
+                    println!("  {}", addr);
+                }
+            }
         }
 
         Commands::Validate {
@@ -340,6 +384,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             if let Some(zone) = addr.zone_id() {
                 println!("  Zone ID:      {}", zone);
             }
+            if let Some(scope) = addr.multicast_scope() {
+                println!("  Multicast Scope: {}", scope);
+            }
+            if let Some(flags) = addr.multicast_flags() {
+                println!("  Multicast Flags: {}", flags);
+            }
+        }
+
+        Commands::Aggregate { networks } => {
+            let refs: Vec<&str> = networks.iter().map(String::as_str).collect();
+            let aggregated = aggregate_networks(&refs)?;
+
+            if cli.format == "json" {
+                println!("{}", serde_json::to_string_pretty(&aggregated)?);
+            } else {
+                println!("Aggregated into {} network(s):", aggregated.len());
+                for net in &aggregated {
+                    println!("  {}", net.network);
+                }
+            }
         }
     }
 